@@ -0,0 +1,269 @@
+use crate::content::GeneratedContent;
+use crate::database::CostRecord;
+use crate::session::{Session, SessionManager};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tar::{Archive as TarArchive, Builder as TarBuilder, Header};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Current on-disk schema version for portable session archives.
+/// Bump this and add a branch to `migrate_payload` whenever the archive layout changes.
+pub const ARCHIVE_SCHEMA_VERSION: u32 = 1;
+
+const ARCHIVE_ENTRY_NAME: &str = "session.json";
+
+/// Header stored inside the session payload, describing the archive's schema version and
+/// contents. `import_session` reads it after parsing the whole `session.json` entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveHeader {
+    pub schema_version: u32,
+    pub session_id: Uuid,
+    pub session_name: String,
+    pub exported_at: DateTime<Utc>,
+    pub content_count: usize,
+    pub cost_record_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ArchivePayload {
+    header: ArchiveHeader,
+    session: Session,
+    content: Vec<GeneratedContent>,
+    cost_records: Vec<CostRecord>,
+}
+
+/// How `import_session` should handle a session id that already exists locally.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum IdConflictPolicy {
+    /// Always import as a new session with a freshly generated id (the default).
+    Remap,
+    /// Keep the original id, failing if a session with that id already exists.
+    Preserve,
+}
+
+/// Progress update emitted while exporting or importing a session archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveProgress {
+    pub session_id: String,
+    pub current_operation: String,
+    pub processed_bytes: u64,
+    pub total_bytes: u64,
+    pub percent: f32,
+}
+
+/// Shared cancellation flag for a running export/import, handed out to callers
+/// so a long-running archive task can be aborted from another command invocation.
+#[derive(Clone, Default)]
+pub struct ArchiveCancelToken(Arc<AtomicBool>);
+
+impl ArchiveCancelToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+fn send_progress(
+    sender: &Option<mpsc::UnboundedSender<ArchiveProgress>>,
+    session_id: &str,
+    current_operation: &str,
+    processed_bytes: u64,
+    total_bytes: u64,
+) {
+    if let Some(sender) = sender {
+        let percent = if total_bytes > 0 {
+            (processed_bytes as f32 / total_bytes as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        let _ = sender.send(ArchiveProgress {
+            session_id: session_id.to_string(),
+            current_operation: current_operation.to_string(),
+            processed_bytes,
+            total_bytes,
+            percent,
+        });
+    }
+}
+
+/// Export a session, its generated content and its cost records into a single
+/// self-contained `.tar.zst` archive that `import_session` can reload elsewhere.
+pub async fn export_session(
+    session_manager: &SessionManager,
+    session_id: Uuid,
+    output_path: &Path,
+    progress: Option<mpsc::UnboundedSender<ArchiveProgress>>,
+    cancel: ArchiveCancelToken,
+) -> Result<PathBuf> {
+    let session_id_str = session_id.to_string();
+
+    send_progress(&progress, &session_id_str, "Loading session", 0, 1);
+    let session = session_manager
+        .load_session(session_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id))?;
+    let content = session.generated_content.clone();
+    let cost_records = session_manager.get_session_cost_records(session_id).await?;
+
+    if cancel.is_cancelled() {
+        bail!("Export cancelled for session {}", session_id);
+    }
+
+    // `calculate_session_size` gives us an estimate of the payload size before we've
+    // serialized it, so the progress bar has a meaningful total from the first update.
+    let estimated_bytes = (crate::session::commands::calculate_session_size(&session)
+        * 1024.0
+        * 1024.0) as u64;
+
+    let header = ArchiveHeader {
+        schema_version: ARCHIVE_SCHEMA_VERSION,
+        session_id,
+        session_name: session.name.clone(),
+        exported_at: Utc::now(),
+        content_count: content.len(),
+        cost_record_count: cost_records.len(),
+    };
+    let payload = ArchivePayload {
+        header,
+        session,
+        content,
+        cost_records,
+    };
+
+    send_progress(&progress, &session_id_str, "Serializing session", 0, estimated_bytes.max(1));
+    let payload_json =
+        serde_json::to_vec_pretty(&payload).context("Failed to serialize session archive")?;
+    let total_bytes = payload_json.len() as u64;
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create archive output directory")?;
+    }
+
+    send_progress(&progress, &session_id_str, "Compressing archive", 0, total_bytes);
+    let file = std::fs::File::create(output_path).context("Failed to create archive file")?;
+    let encoder = zstd::stream::write::Encoder::new(file, 0)
+        .context("Failed to initialize archive compression")?;
+    let mut tar_builder = TarBuilder::new(encoder);
+
+    let mut header = Header::new_gnu();
+    header.set_size(total_bytes);
+    header.set_cksum();
+    tar_builder
+        .append_data(&mut header, ARCHIVE_ENTRY_NAME, payload_json.as_slice())
+        .context("Failed to write session archive entry")?;
+    tar_builder
+        .into_inner()
+        .context("Failed to finalize session archive")?
+        .finish()
+        .context("Failed to finalize archive compression")?;
+
+    send_progress(&progress, &session_id_str, "Export complete", total_bytes, total_bytes);
+
+    Ok(output_path.to_path_buf())
+}
+
+/// Import a previously-exported session archive, reloading the session,
+/// its content and its cost records into the local `SessionManager`.
+pub async fn import_session(
+    session_manager: &SessionManager,
+    archive_path: &Path,
+    conflict_policy: IdConflictPolicy,
+    progress: Option<mpsc::UnboundedSender<ArchiveProgress>>,
+    cancel: ArchiveCancelToken,
+) -> Result<Uuid> {
+    send_progress(&progress, "unknown", "Opening archive", 0, 1);
+
+    let file = std::fs::File::open(archive_path).context("Failed to open session archive")?;
+    let decoder =
+        zstd::stream::read::Decoder::new(file).context("Failed to read archive compression")?;
+    let mut tar_archive = TarArchive::new(decoder);
+
+    let mut payload: Option<ArchivePayload> = None;
+    for entry in tar_archive.entries().context("Failed to read archive entries")? {
+        let mut entry = entry.context("Failed to read archive entry")?;
+        let entry_path = entry.path().context("Failed to read archive entry path")?.to_path_buf();
+        if entry_path.to_string_lossy() == ARCHIVE_ENTRY_NAME {
+            let mut buf = Vec::new();
+            entry.read_to_end(&mut buf).context("Failed to read session archive entry")?;
+            payload = Some(
+                serde_json::from_slice(&buf).context("Failed to parse session archive entry")?,
+            );
+        }
+    }
+
+    let payload =
+        payload.ok_or_else(|| anyhow::anyhow!("Archive is missing its {} entry", ARCHIVE_ENTRY_NAME))?;
+    let payload = migrate_payload(payload)?;
+    let session_id_str = payload.header.session_id.to_string();
+
+    if cancel.is_cancelled() {
+        bail!("Import cancelled for archive {}", archive_path.display());
+    }
+
+    send_progress(&progress, &session_id_str, "Restoring session", 0, 1);
+
+    let restored_id = match conflict_policy {
+        IdConflictPolicy::Remap => {
+            session_manager
+                .create_session_from_backup(payload.session, payload.content)
+                .await
+                .context("Failed to restore session from archive")?
+        }
+        IdConflictPolicy::Preserve => {
+            if session_manager.get_session(payload.session.id).await?.is_some() {
+                bail!(
+                    "Session {} already exists locally; use IdConflictPolicy::Remap to import as a new session",
+                    payload.session.id
+                );
+            }
+            session_manager
+                .restore_session_preserving_id(payload.session, payload.content)
+                .await
+                .context("Failed to restore session from archive")?
+        }
+    };
+
+    send_progress(&progress, &restored_id.to_string(), "Restoring cost history", 0, 1);
+    session_manager
+        .restore_cost_records(restored_id, payload.cost_records)
+        .await
+        .context("Failed to restore session cost records from archive")?;
+
+    send_progress(&progress, &restored_id.to_string(), "Import complete", 1, 1);
+
+    Ok(restored_id)
+}
+
+/// Upgrade an archive payload read from disk to the current schema version.
+/// Each past schema bump gets its own match arm here rather than being handled inline.
+fn migrate_payload(mut payload: ArchivePayload) -> Result<ArchivePayload> {
+    if payload.header.schema_version > ARCHIVE_SCHEMA_VERSION {
+        bail!(
+            "Archive schema version {} is newer than the supported version {}; update the application",
+            payload.header.schema_version,
+            ARCHIVE_SCHEMA_VERSION
+        );
+    }
+
+    match payload.header.schema_version {
+        ARCHIVE_SCHEMA_VERSION => {}
+        other => bail!("No migration path from archive schema version {}", other),
+    }
+
+    payload.header.schema_version = ARCHIVE_SCHEMA_VERSION;
+    Ok(payload)
+}
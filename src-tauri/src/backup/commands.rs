@@ -1,7 +1,10 @@
+use super::archive::IdConflictPolicy;
 use super::{BackupConfig, BackupType, BackupFilter, BackupListItem, BackupStatistics};
 use super::service::BackupService;
-use tauri::State;
+use tauri::{State, Window, Emitter};
 use std::sync::Arc;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
 
 #[tauri::command]
 pub async fn create_manual_backup(
@@ -123,4 +126,67 @@ pub async fn get_session_backups(
         .list_backups(Some(filter))
         .await
         .map_err(|e| e.to_string())
+}
+
+/// Export a session as a portable, self-contained archive (tar+zstd), emitting
+/// `archive-export-progress` events so the frontend can drive a progress bar and offer cancellation.
+#[tauri::command]
+pub async fn export_session_archive(
+    backup_service: State<'_, Arc<BackupService>>,
+    session_id: String,
+    task_id: String,
+    window: Window,
+) -> Result<String, String> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let progress_window = window.clone();
+    tokio::spawn(async move {
+        while let Some(progress) = rx.recv().await {
+            let _ = progress_window.emit("archive-export-progress", &progress);
+        }
+    });
+
+    backup_service
+        .export_session_archive(&session_id, &task_id, Some(tx))
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Import a previously-exported session archive, emitting `archive-import-progress` events.
+#[tauri::command]
+pub async fn import_session_archive(
+    backup_service: State<'_, Arc<BackupService>>,
+    archive_path: String,
+    preserve_ids: bool,
+    task_id: String,
+    window: Window,
+) -> Result<String, String> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let progress_window = window.clone();
+    tokio::spawn(async move {
+        while let Some(progress) = rx.recv().await {
+            let _ = progress_window.emit("archive-import-progress", &progress);
+        }
+    });
+
+    let conflict_policy = if preserve_ids {
+        IdConflictPolicy::Preserve
+    } else {
+        IdConflictPolicy::Remap
+    };
+
+    backup_service
+        .import_session_archive(&PathBuf::from(archive_path), conflict_policy, &task_id, Some(tx))
+        .await
+        .map(|id| id.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Cancel a running `export_session_archive`/`import_session_archive` task.
+#[tauri::command]
+pub async fn cancel_archive_task(
+    backup_service: State<'_, Arc<BackupService>>,
+    task_id: String,
+) -> Result<bool, String> {
+    Ok(backup_service.cancel_archive_task(&task_id).await)
 }
\ No newline at end of file
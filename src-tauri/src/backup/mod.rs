@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 pub mod service;
 pub mod scheduler;
 pub mod commands;
+pub mod archive;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackupConfig {
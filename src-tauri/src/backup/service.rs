@@ -1,3 +1,4 @@
+use super::archive::{self, ArchiveCancelToken, ArchiveProgress, IdConflictPolicy};
 use super::{BackupConfig, BackupMetadata, BackupType, BackupListItem, BackupFilter, BackupStatistics};
 use crate::session::{SessionManager, Session};
 use crate::content::GeneratedContent;
@@ -8,7 +9,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, Mutex};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use sha2::{Sha256, Digest};
@@ -19,6 +20,7 @@ pub struct BackupService {
     file_service: Arc<Mutex<FileService>>,
     config: Arc<Mutex<BackupConfig>>,
     backup_metadata: Arc<Mutex<HashMap<String, BackupMetadata>>>,
+    archive_tasks: Arc<Mutex<HashMap<String, ArchiveCancelToken>>>,
 }
 
 impl BackupService {
@@ -31,6 +33,7 @@ impl BackupService {
             file_service,
             config: Arc::new(Mutex::new(BackupConfig::default())),
             backup_metadata: Arc::new(Mutex::new(HashMap::new())),
+            archive_tasks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -425,4 +428,67 @@ impl BackupService {
         let current_checksum = self.calculate_checksum(&metadata.file_path).await?;
         Ok(current_checksum == metadata.checksum)
     }
+
+    /// Export a session as a portable, self-contained archive (tar+zstd), registering a
+    /// cancel token under `task_id` so a caller can abort a large export in progress.
+    pub async fn export_session_archive(
+        &self,
+        session_id: &str,
+        task_id: &str,
+        progress: Option<mpsc::UnboundedSender<ArchiveProgress>>,
+    ) -> Result<PathBuf> {
+        let session_uuid = Uuid::parse_str(session_id).context("Invalid session ID format")?;
+
+        let file_service = self.file_service.lock().await;
+        let backup_dir = file_service.get_storage_paths().await?.backups;
+        let output_path = backup_dir.join(format!("{}_session_{}.tar.zst", session_id, task_id));
+        drop(file_service);
+
+        let cancel = ArchiveCancelToken::new();
+        self.archive_tasks
+            .lock()
+            .await
+            .insert(task_id.to_string(), cancel.clone());
+
+        let session_manager = self.session_manager.lock().await;
+        let result =
+            archive::export_session(&session_manager, session_uuid, &output_path, progress, cancel)
+                .await;
+
+        self.archive_tasks.lock().await.remove(task_id);
+        result
+    }
+
+    /// Import a previously-exported session archive, registering a cancel token under `task_id`.
+    pub async fn import_session_archive(
+        &self,
+        archive_path: &Path,
+        conflict_policy: IdConflictPolicy,
+        task_id: &str,
+        progress: Option<mpsc::UnboundedSender<ArchiveProgress>>,
+    ) -> Result<Uuid> {
+        let cancel = ArchiveCancelToken::new();
+        self.archive_tasks
+            .lock()
+            .await
+            .insert(task_id.to_string(), cancel.clone());
+
+        let session_manager = self.session_manager.lock().await;
+        let result =
+            archive::import_session(&session_manager, archive_path, conflict_policy, progress, cancel)
+                .await;
+
+        self.archive_tasks.lock().await.remove(task_id);
+        result
+    }
+
+    /// Cancel a running archive export/import task. Returns `false` if no such task is running.
+    pub async fn cancel_archive_task(&self, task_id: &str) -> bool {
+        if let Some(cancel) = self.archive_tasks.lock().await.get(task_id) {
+            cancel.cancel();
+            true
+        } else {
+            false
+        }
+    }
 }
\ No newline at end of file
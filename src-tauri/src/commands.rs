@@ -745,6 +745,7 @@ pub async fn create_batch_export_job(
     template_name: Option<String>,
     include_metadata: Option<bool>,
     branding_options: Option<serde_json::Value>,
+    priority: Option<u8>,
 ) -> Result<serde_json::Value, AppError> {
     use crate::export::{BatchExportJob, ExportFormat, NamingStrategy, BrandingOptions};
     
@@ -815,6 +816,7 @@ pub async fn create_batch_export_job(
         template_name,
         include_metadata: include_metadata.unwrap_or(true),
         branding_options: branding,
+        priority: priority.unwrap_or(0),
     };
 
     serde_json::to_value(job)
@@ -2,6 +2,7 @@ use anyhow::Result;
 use uuid::Uuid;
 use sqlx::{SqlitePool, Row, migrate::MigrateDatabase};
 use serde_json;
+use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 
@@ -249,6 +250,25 @@ impl Database {
         Ok(())
     }
 
+    // Re-insert a previously recorded usage row verbatim, e.g. when restoring a
+    // session archive's cost history rather than recording a fresh LLM call.
+    pub async fn insert_cost_record(&self, record: &CostRecord) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO llm_usage (id, session_id, provider_id, tokens_used, cost_usd, request_type, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(record.id.to_string())
+        .bind(record.session_id.to_string())
+        .bind(&record.provider_id)
+        .bind(record.tokens_used as i64)
+        .bind(record.cost_usd)
+        .bind(&record.request_type)
+        .bind(record.created_at.to_rfc3339())
+        .execute(&self.pool).await?;
+
+        Ok(())
+    }
+
     pub async fn get_total_cost(&self, session_id: Option<Uuid>) -> Result<f64> {
         let cost_row = if let Some(session_id) = session_id {
             sqlx::query(
@@ -262,7 +282,114 @@ impl Database {
             )
             .fetch_one(&self.pool).await?
         };
-        
+
         Ok(cost_row.get::<f64, _>("total_cost"))
     }
+
+    // Individual usage rows for a session, e.g. for archiving or auditing cost history
+    pub async fn get_session_cost_records(&self, session_id: Uuid) -> Result<Vec<CostRecord>> {
+        let rows = sqlx::query(
+            "SELECT id, session_id, provider_id, tokens_used, cost_usd, request_type, created_at
+             FROM llm_usage WHERE session_id = ? ORDER BY created_at"
+        )
+        .bind(session_id.to_string())
+        .fetch_all(&self.pool).await?;
+
+        let mut records = Vec::new();
+        for row in rows {
+            let id_str: String = row.get("id");
+            let session_id_str: String = row.get("session_id");
+            let created_at_str: String = row.get("created_at");
+
+            records.push(CostRecord {
+                id: Uuid::parse_str(&id_str)?,
+                session_id: Uuid::parse_str(&session_id_str)?,
+                provider_id: row.get("provider_id"),
+                tokens_used: row.get::<i64, _>("tokens_used") as u32,
+                cost_usd: row.get("cost_usd"),
+                request_type: row.get("request_type"),
+                created_at: DateTime::parse_from_rfc3339(&created_at_str)?.with_timezone(&Utc),
+            });
+        }
+
+        Ok(records)
+    }
+
+    // Find llm_usage rows and generated_content rows whose parent session no longer exists
+    pub async fn find_orphaned_content_and_cost_ids(&self) -> Result<(Vec<String>, Vec<String>)> {
+        let orphaned_content_rows = sqlx::query(
+            "SELECT gc.id FROM generated_content gc
+             LEFT JOIN sessions s ON gc.session_id = s.id WHERE s.id IS NULL"
+        )
+        .fetch_all(&self.pool).await?;
+
+        let orphaned_cost_rows = sqlx::query(
+            "SELECT lu.id FROM llm_usage lu
+             LEFT JOIN sessions s ON lu.session_id = s.id WHERE s.id IS NULL"
+        )
+        .fetch_all(&self.pool).await?;
+
+        let orphaned_content_ids = orphaned_content_rows.into_iter().map(|r| r.get::<String, _>("id")).collect();
+        let orphaned_cost_ids = orphaned_cost_rows.into_iter().map(|r| r.get::<String, _>("id")).collect();
+
+        Ok((orphaned_content_ids, orphaned_cost_ids))
+    }
+
+    /// Ids of `generated_content` rows for `session_id` whose `content` column isn't valid
+    /// `GeneratedContent` JSON. `get_session_content` silently skips these rows while mapping,
+    /// but `count_session_content`'s raw `COUNT(*)` still counts them — the gap between the two
+    /// is exactly these ids, so `repair_sessions` can delete them to resolve a `ContentCountMismatch`.
+    pub async fn find_malformed_content_ids(&self, session_id: Uuid) -> Result<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT id, content FROM generated_content WHERE session_id = ?"
+        )
+        .bind(session_id.to_string())
+        .fetch_all(&self.pool).await?;
+
+        Ok(rows.into_iter()
+            .filter(|row| serde_json::from_str::<GeneratedContent>(&row.get::<String, _>("content")).is_err())
+            .map(|row| row.get::<String, _>("id"))
+            .collect())
+    }
+
+    pub async fn delete_generated_content_by_ids(&mut self, ids: &[String]) -> Result<u64> {
+        let mut deleted = 0;
+        for id in ids {
+            let result = sqlx::query("DELETE FROM generated_content WHERE id = ?")
+                .bind(id)
+                .execute(&self.pool).await?;
+            deleted += result.rows_affected();
+        }
+        Ok(deleted)
+    }
+
+    pub async fn delete_llm_usage_by_ids(&mut self, ids: &[String]) -> Result<u64> {
+        let mut deleted = 0;
+        for id in ids {
+            let result = sqlx::query("DELETE FROM llm_usage WHERE id = ?")
+                .bind(id)
+                .execute(&self.pool).await?;
+            deleted += result.rows_affected();
+        }
+        Ok(deleted)
+    }
+
+    pub async fn count_session_content(&self, session_id: Uuid) -> Result<u32> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM generated_content WHERE session_id = ?")
+            .bind(session_id.to_string())
+            .fetch_one(&self.pool).await?;
+        Ok(row.get::<i64, _>("count") as u32)
+    }
+}
+
+/// A single recorded LLM usage/cost entry, as stored in `llm_usage`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostRecord {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub provider_id: String,
+    pub tokens_used: u32,
+    pub cost_usd: Option<f64>,
+    pub request_type: String,
+    pub created_at: DateTime<Utc>,
 }
\ No newline at end of file
@@ -1,18 +1,43 @@
-use super::{ExportManager, BatchExportJob, BatchExportOptions, BatchExportResult, JobResult, ExportOptions, ExportFormat, BatchProgress, NamingStrategy};
+use super::{ExportManager, BatchExportJob, BatchExportOptions, BatchExportResult, JobResult, ExportOptions, ExportFormat, BatchProgress, NamingStrategy, RetryPolicy};
 use crate::content::GeneratedContent;
 use crate::session::SessionManager;
 use anyhow::{Result, Context};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use tokio::sync::mpsc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 use serde_json;
 
+/// How often `watch_and_export` polls sessions for content changes.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a session's content hash must remain stable before its dependent jobs are
+/// re-exported, so rapid successive edits coalesce into a single re-export.
+const WATCH_DEBOUNCE: Duration = Duration::from_secs(2);
+/// Path `create_manifest` and incremental export checks read and write the batch manifest at.
+const MANIFEST_FILENAME: &str = "batch_export_manifest.json";
+/// Default concurrency for jobs submitted through `append_job`, matching
+/// `BatchExportOptions::default().max_concurrent_jobs`.
+const DEFAULT_APPEND_CONCURRENCY: usize = 4;
+
+/// A job spawned via `append_job`, tracked until `poll_completed` drains it or `cancel` aborts it.
+struct RunningJob {
+    job_id: String,
+    handle: JoinHandle<Result<JobResult>>,
+}
+
 pub struct BatchExportManager {
     export_manager: ExportManager,
     session_manager: SessionManager,
     progress_sender: Option<mpsc::UnboundedSender<BatchProgress>>,
+    append_concurrency: Arc<Semaphore>,
+    running_jobs: Arc<Mutex<HashMap<Uuid, RunningJob>>>,
+    cancelled_jobs: Arc<Mutex<HashMap<Uuid, JobResult>>>,
+    watch_tasks: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
 }
 
 impl BatchExportManager {
@@ -21,6 +46,10 @@ impl BatchExportManager {
             export_manager: ExportManager::new(),
             session_manager,
             progress_sender: None,
+            append_concurrency: Arc::new(Semaphore::new(DEFAULT_APPEND_CONCURRENCY)),
+            running_jobs: Arc::new(Mutex::new(HashMap::new())),
+            cancelled_jobs: Arc::new(Mutex::new(HashMap::new())),
+            watch_tasks: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -29,6 +58,139 @@ impl BatchExportManager {
         self
     }
 
+    /// Overrides how many `append_job` submissions may run concurrently (default
+    /// `DEFAULT_APPEND_CONCURRENCY`). Has no effect on `execute_batch_export`, which sizes its
+    /// own semaphore from `BatchExportOptions::max_concurrent_jobs`.
+    pub fn with_append_concurrency(mut self, max_concurrent_jobs: usize) -> Self {
+        self.append_concurrency = Arc::new(Semaphore::new(max_concurrent_jobs));
+        self
+    }
+
+    /// A lightweight clone used for tasks spawned off `self` (parallel batch jobs, appended
+    /// jobs): each gets its own `ExportManager` since its converters aren't `Clone`, but shares
+    /// the progress channel and job-tracking state.
+    fn for_task(&self) -> Self {
+        Self {
+            export_manager: ExportManager::new(),
+            session_manager: self.session_manager.clone(),
+            progress_sender: self.progress_sender.clone(),
+            append_concurrency: Arc::clone(&self.append_concurrency),
+            running_jobs: Arc::clone(&self.running_jobs),
+            cancelled_jobs: Arc::clone(&self.cancelled_jobs),
+            watch_tasks: Arc::clone(&self.watch_tasks),
+        }
+    }
+
+    /// Spawns `watch_and_export` in the background under a generated task id and returns that id
+    /// immediately, so a caller (e.g. a Tauri command) isn't blocked on a loop that runs until
+    /// cancelled. Call `cancel_watch` with the returned id to stop it.
+    pub fn start_watch(&self, jobs: Vec<BatchExportJob>, options: BatchExportOptions) -> String {
+        let task_id = Uuid::new_v4().to_string();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.watch_tasks.lock().unwrap().insert(task_id.clone(), Arc::clone(&cancel));
+
+        let batch_manager = self.for_task();
+        let watch_tasks = Arc::clone(&self.watch_tasks);
+        let cleanup_task_id = task_id.clone();
+        tokio::spawn(async move {
+            if let Err(e) = batch_manager.watch_and_export(jobs, options, cancel).await {
+                eprintln!("Watch export task {} failed: {}", cleanup_task_id, e);
+            }
+            watch_tasks.lock().unwrap().remove(&cleanup_task_id);
+        });
+
+        task_id
+    }
+
+    /// Stops a running `start_watch` task. A no-op if `task_id` isn't a running watch task
+    /// (already stopped, or never started).
+    pub fn cancel_watch(&self, task_id: &str) -> bool {
+        match self.watch_tasks.lock().unwrap().get(task_id) {
+            Some(cancel) => {
+                cancel.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Spawns `job` immediately under `append_concurrency` and returns a handle id, without
+    /// waiting for the job to run. Call `poll_completed` to collect the `JobResult` once it
+    /// finishes, or `cancel` to abort it mid-flight. Unlike `execute_batch_export`, this never
+    /// blocks the caller on another job's completion, so a GUI can submit a batch of jobs and
+    /// keep rendering a live queue.
+    pub fn append_job(&self, job: BatchExportJob, retry_policy: RetryPolicy) -> Uuid {
+        let handle_id = Uuid::new_v4();
+        let job_id = job.job_id.clone();
+        let batch_manager = self.for_task();
+        let permit_source = Arc::clone(&self.append_concurrency);
+
+        let handle = tokio::spawn(async move {
+            let _permit = permit_source
+                .acquire_owned()
+                .await
+                .context("Append concurrency semaphore was closed")?;
+            batch_manager.execute_single_job(job, &retry_policy, false).await
+        });
+
+        self.running_jobs
+            .lock()
+            .unwrap()
+            .insert(handle_id, RunningJob { job_id, handle });
+
+        handle_id
+    }
+
+    /// Drains jobs that have finished or been cancelled since the last call, without blocking
+    /// on jobs still in flight.
+    pub async fn poll_completed(&self) -> Vec<(Uuid, JobResult)> {
+        let mut completed: Vec<(Uuid, JobResult)> =
+            self.cancelled_jobs.lock().unwrap().drain().collect();
+
+        let finished_ids: Vec<Uuid> = {
+            let running = self.running_jobs.lock().unwrap();
+            running
+                .iter()
+                .filter(|(_, running_job)| running_job.handle.is_finished())
+                .map(|(&id, _)| id)
+                .collect()
+        };
+
+        for id in finished_ids {
+            let running_job = {
+                let mut running = self.running_jobs.lock().unwrap();
+                running.remove(&id)
+            };
+            let Some(running_job) = running_job else { continue };
+
+            completed.push((id, await_finished_job(running_job).await));
+        }
+
+        completed
+    }
+
+    /// Aborts the running handle for `id` and records a cancelled `JobResult` that the next
+    /// `poll_completed` call will return for it. A no-op if `id` isn't a running job (already
+    /// completed, already cancelled, or never submitted).
+    ///
+    /// A job can finish between the caller's last `poll_completed` and this call, in which case
+    /// `handle.abort()` would be a no-op anyway — so rather than synthesizing a cancellation that
+    /// would discard the job's real result, this checks `is_finished()` first and, if the job
+    /// already completed, reports its actual `JobResult` instead.
+    pub async fn cancel(&self, id: Uuid) {
+        let running_job = self.running_jobs.lock().unwrap().remove(&id);
+        let Some(running_job) = running_job else { return };
+
+        let job_result = if running_job.handle.is_finished() {
+            await_finished_job(running_job).await
+        } else {
+            let job_id = running_job.job_id.clone();
+            running_job.handle.abort();
+            failed_job_result(job_id, "Job cancelled".to_string())
+        };
+        self.cancelled_jobs.lock().unwrap().insert(id, job_result);
+    }
+
     pub async fn execute_batch_export(
         &self,
         jobs: Vec<BatchExportJob>,
@@ -96,6 +258,119 @@ impl BatchExportManager {
         Ok(result)
     }
 
+    /// Run `jobs` once, then keep watching the sessions they depend on and re-export only the
+    /// jobs affected by a changed session, until `cancel` is set. Mirrors the debounced
+    /// file-watcher loop used by test runners: a session's content hash must settle for
+    /// `WATCH_DEBOUNCE` before its dependent jobs are re-run, so a burst of edits to the same
+    /// session only triggers one re-export.
+    pub async fn watch_and_export(
+        &self,
+        jobs: Vec<BatchExportJob>,
+        options: BatchExportOptions,
+        cancel: Arc<AtomicBool>,
+    ) -> Result<()> {
+        self.execute_batch_export(jobs.clone(), options.clone()).await?;
+
+        let mut dependents: HashMap<Uuid, Vec<String>> = HashMap::new();
+        let mut jobs_by_id: HashMap<String, BatchExportJob> = HashMap::new();
+        for job in jobs {
+            for session_id_str in &job.session_ids {
+                if let Ok(session_uuid) = Uuid::parse_str(session_id_str) {
+                    dependents
+                        .entry(session_uuid)
+                        .or_insert_with(Vec::new)
+                        .push(job.job_id.clone());
+                }
+            }
+            jobs_by_id.insert(job.job_id.clone(), job);
+        }
+
+        let mut last_hashes: HashMap<Uuid, String> = HashMap::new();
+        for &session_uuid in dependents.keys() {
+            let hash = self.session_content_hash(session_uuid).await.unwrap_or_default();
+            last_hashes.insert(session_uuid, hash);
+        }
+
+        let mut pending_since: HashMap<Uuid, Instant> = HashMap::new();
+        let mut poll_interval = tokio::time::interval(WATCH_POLL_INTERVAL);
+
+        loop {
+            poll_interval.tick().await;
+
+            if cancel.load(Ordering::SeqCst) {
+                break;
+            }
+
+            for &session_uuid in dependents.keys() {
+                let hash = match self.session_content_hash(session_uuid).await {
+                    Ok(hash) => hash,
+                    Err(_) => continue,
+                };
+
+                let changed = last_hashes
+                    .get(&session_uuid)
+                    .map(|previous| *previous != hash)
+                    .unwrap_or(true);
+
+                if changed {
+                    last_hashes.insert(session_uuid, hash);
+                    pending_since.insert(session_uuid, Instant::now());
+                }
+            }
+
+            let ready_sessions: Vec<Uuid> = pending_since
+                .iter()
+                .filter(|(_, since)| since.elapsed() >= WATCH_DEBOUNCE)
+                .map(|(&session_uuid, _)| session_uuid)
+                .collect();
+
+            let mut job_ids_to_rerun: Vec<String> = Vec::new();
+            for session_uuid in ready_sessions {
+                pending_since.remove(&session_uuid);
+                if let Some(dependent_job_ids) = dependents.get(&session_uuid) {
+                    for job_id in dependent_job_ids {
+                        if !job_ids_to_rerun.contains(job_id) {
+                            job_ids_to_rerun.push(job_id.clone());
+                        }
+                    }
+                }
+            }
+
+            for job_id in job_ids_to_rerun {
+                let job = match jobs_by_id.get(&job_id) {
+                    Some(job) => job.clone(),
+                    None => continue,
+                };
+
+                self.send_progress(BatchProgress {
+                    total_jobs: 1,
+                    completed_jobs: 0,
+                    current_job_id: Some(job_id.clone()),
+                    current_operation: format!(
+                        "Re-exporting job {} after source session change",
+                        job_id
+                    ),
+                    progress_percent: 0.0,
+                    estimated_completion: None,
+                    errors_encountered: 0,
+                });
+
+                if let Err(e) = self.execute_single_job(job, &options.retry_policy, options.incremental).await {
+                    eprintln!("Failed to re-export job {} after session change: {}", job_id, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// SHA-256 of a session's concatenated generated content, used by `watch_and_export` to
+    /// detect when a session's content has changed since the last poll.
+    async fn session_content_hash(&self, session_id: Uuid) -> Result<String> {
+        let content = self.session_manager.get_session_content(session_id).await?;
+        Ok(content_checksum(&content))
+    }
+
     async fn execute_sequential_jobs(
         &self,
         jobs: Vec<BatchExportJob>,
@@ -115,7 +390,7 @@ impl BatchExportManager {
                 errors_encountered: results.iter().filter(|r| !r.success).count(),
             });
 
-            match self.execute_single_job(job).await {
+            match self.execute_single_job(job, &options.retry_policy, options.incremental).await {
                 Ok(result) => results.push(result),
                 Err(e) => {
                     if !options.continue_on_error {
@@ -128,6 +403,7 @@ impl BatchExportManager {
                         error_message: Some(e.to_string()),
                         files_created: 0,
                         total_size: 0,
+                        source_checksum: None,
                     });
                 }
             }
@@ -153,19 +429,14 @@ impl BatchExportManager {
             let permit = semaphore.clone().acquire_owned().await.unwrap();
             let progress_clone = Arc::clone(&progress);
             let _job_id = job.job_id.clone();
-            let export_manager = ExportManager::new(); // Each task gets its own manager
-            let session_manager = self.session_manager.clone();
+            let batch_manager = self.for_task();
+            let retry_policy = options.retry_policy.clone();
+            let incremental = options.incremental;
 
             join_set.spawn(async move {
                 let _permit = permit; // Hold the permit for the duration of the task
-                
-                let batch_manager = BatchExportManager {
-                    export_manager,
-                    session_manager,
-                    progress_sender: None,
-                };
 
-                let result = batch_manager.execute_single_job(job).await;
+                let result = batch_manager.execute_single_job(job, &retry_policy, incremental).await;
                 
                 // Update progress
                 {
@@ -195,6 +466,7 @@ impl BatchExportManager {
                                 error_message: Some(e.to_string()),
                                 files_created: 0,
                                 total_size: 0,
+                                source_checksum: None,
                             });
                         }
                     }
@@ -226,36 +498,54 @@ impl BatchExportManager {
         Ok(results)
     }
 
-    async fn execute_single_job(&self, job: BatchExportJob) -> Result<JobResult> {
+    /// The unit of work a `BatchExportQueue` invokes for each queued job. When `incremental` is
+    /// set, a `(job, format)` pair whose source checksum and output file both match the last
+    /// manifest entry is skipped and its prior `ExportResult` is reused instead of re-rendering.
+    /// When `job.merge_sessions` is `false`, each session is exported as its own set of files
+    /// rather than being combined into one; see `generate_filename` for how output names
+    /// disambiguate between sessions in that case.
+    pub(crate) async fn execute_single_job(
+        &self,
+        job: BatchExportJob,
+        retry_policy: &RetryPolicy,
+        incremental: bool,
+    ) -> Result<JobResult> {
         let job_id = job.job_id.clone();
-        
-        // Retrieve content for all sessions
-        let mut all_content = Vec::new();
+
+        // Retrieve content for each session individually
+        let mut session_content: Vec<(String, Vec<GeneratedContent>)> = Vec::new();
 
         for session_id_str in &job.session_ids {
             let session_uuid = Uuid::parse_str(session_id_str)
                 .context(format!("Invalid session ID: {}", session_id_str))?;
-            
+
             let _session = self.session_manager.get_session(session_uuid).await
                 .context(format!("Failed to get session: {}", session_id_str))?
                 .ok_or_else(|| anyhow::anyhow!("Session not found: {}", session_id_str))?;
-            
+
             let content = self.session_manager.get_session_content(session_uuid).await
                 .context(format!("Failed to get content for session: {}", session_id_str))?;
-            
-            all_content.extend(content);
+
+            session_content.push((session_id_str.clone(), content));
         }
 
-        // Determine content to export
-        let export_content = if job.merge_sessions {
-            all_content
+        // A merged job exports one combined set of content; a fan-out job (`merge_sessions ==
+        // false`) exports each session's content as its own set, tagged with that session's id.
+        let groups: Vec<(Option<String>, Vec<GeneratedContent>)> = if job.merge_sessions {
+            let merged = session_content.into_iter().flat_map(|(_, content)| content).collect();
+            vec![(None, merged)]
         } else {
-            // For non-merged sessions, we'll export each session separately
-            // For now, we'll merge them but this could be enhanced
-            all_content
+            session_content
+                .into_iter()
+                .map(|(session_id, content)| (Some(session_id), content))
+                .collect()
         };
+        let groups: Vec<(Option<String>, Vec<GeneratedContent>)> = groups
+            .into_iter()
+            .filter(|(_, content)| !content.is_empty())
+            .collect();
 
-        if export_content.is_empty() {
+        if groups.is_empty() {
             return Ok(JobResult {
                 job_id,
                 success: false,
@@ -263,39 +553,102 @@ impl BatchExportManager {
                 error_message: Some("No content found for specified sessions".to_string()),
                 files_created: 0,
                 total_size: 0,
+                source_checksum: None,
             });
         }
 
-        // Export to all requested formats
+        let all_content: Vec<GeneratedContent> =
+            groups.iter().flat_map(|(_, content)| content.iter().cloned()).collect();
+        let source_checksum = content_checksum(&all_content);
+        let prior_manifest_entry = if incremental {
+            read_manifest_jobs().remove(&job.job_id)
+        } else {
+            None
+        };
+
+        // Export every (session group, format) pair. Each group's checksum is computed
+        // independently so an incremental run only re-renders the groups whose content
+        // actually changed, instead of invalidating every session in a fan-out job whenever any
+        // one of them is edited.
         let mut export_results = Vec::new();
         let mut total_size = 0u64;
 
-        for format in &job.formats {
-            let filename = self.generate_filename(&job, format, &export_content)?;
-            let output_path = job.output_directory.join(filename);
+        for (session_id, content) in &groups {
+            let group_checksum = content_checksum(content);
 
-            let options = ExportOptions {
-                format: format.clone(),
-                output_path: output_path.clone(),
-                template_name: job.template_name.clone(),
-                include_metadata: job.include_metadata,
-                branding_options: job.branding_options.clone(),
-            };
+            for format in &job.formats {
+                let filename = self.generate_filename(&job, format, content, session_id.as_deref())?;
+                let output_path = job.output_directory.join(filename);
 
-            match self.export_manager.export_content(&export_content, &options).await {
-                Ok(result) => {
-                    if let Some(size) = result.file_size {
+                if let Some(mut reused) = prior_manifest_entry.as_ref().and_then(|entry| {
+                    reusable_export(entry, &output_path, &group_checksum)
+                }) {
+                    reused.session_id = session_id.clone();
+                    if let Some(size) = reused.file_size {
                         total_size += size;
                     }
-                    export_results.push(result);
+                    export_results.push(reused);
+                    continue;
                 }
-                Err(e) => {
-                    export_results.push(super::ExportResult {
-                        success: false,
-                        output_path,
-                        file_size: None,
-                        error_message: Some(e.to_string()),
-                    });
+
+                let options = ExportOptions {
+                    format: format.clone(),
+                    output_path: output_path.clone(),
+                    template_name: job.template_name.clone(),
+                    include_metadata: job.include_metadata,
+                    branding_options: job.branding_options.clone(),
+                };
+
+                let mut attempt = 0;
+                let result = loop {
+                    match self.export_manager.export_content(content, &options).await {
+                        Ok(result) => break Ok(result),
+                        Err(e) if is_transient_export_error(&e) && attempt + 1 < retry_policy.max_attempts => {
+                            self.send_progress(BatchProgress {
+                                total_jobs: 1,
+                                completed_jobs: 0,
+                                current_job_id: Some(job.job_id.clone()),
+                                current_operation: format!(
+                                    "Retrying {:?} export for job {} (attempt {} of {})",
+                                    format, job.job_id, attempt + 2, retry_policy.max_attempts
+                                ),
+                                progress_percent: 0.0,
+                                estimated_completion: None,
+                                errors_encountered: 0,
+                            });
+
+                            tokio::time::sleep(retry_policy.delay_for_attempt(attempt)).await;
+                            attempt += 1;
+                        }
+                        Err(e) => break Err(e),
+                    }
+                };
+
+                match result {
+                    Ok(result) => {
+                        if let Some(size) = result.file_size {
+                            total_size += size;
+                        }
+                        let checksum = file_checksum(&result.output_path);
+                        export_results.push(super::ExportResult {
+                            file_checksum: checksum,
+                            session_id: session_id.clone(),
+                            source_checksum: Some(group_checksum.clone()),
+                            ..result
+                        });
+                    }
+                    Err(e) => {
+                        export_results.push(super::ExportResult {
+                            success: false,
+                            output_path,
+                            file_size: None,
+                            error_message: Some(e.to_string()),
+                            file_checksum: None,
+                            skipped: false,
+                            session_id: session_id.clone(),
+                            source_checksum: Some(group_checksum.clone()),
+                        });
+                    }
                 }
             }
         }
@@ -310,34 +663,51 @@ impl BatchExportManager {
             error_message: None,
             files_created,
             total_size,
+            source_checksum: Some(source_checksum),
         })
     }
 
+    /// `session_id` is `Some` when this export belongs to one session out of a fan-out job
+    /// (`merge_sessions == false`), and disambiguates every naming strategy's filename (by
+    /// appending the session id) so each session's files don't collide with one another even
+    /// when they'd otherwise share a name — e.g. `Sequential`'s one-second-resolution timestamp,
+    /// or two sessions whose first content item happens to share a title under `ContentBased`.
+    /// It's `None` for a merged job's combined export.
     fn generate_filename(
         &self,
         job: &BatchExportJob,
         format: &ExportFormat,
         content: &[GeneratedContent],
+        session_id: Option<&str>,
     ) -> Result<String> {
         let extension = self.export_manager.get_default_extension(format);
-        
+
         let base_name = match &job.naming_strategy {
             NamingStrategy::SessionBased => {
-                if job.session_ids.len() == 1 {
+                if let Some(session_id) = session_id {
+                    format!("session_{}", session_id)
+                } else if job.session_ids.len() == 1 {
                     format!("session_{}", job.session_ids[0])
                 } else {
                     format!("sessions_{}", job.session_ids.len())
                 }
             }
             NamingStrategy::ContentBased => {
-                if let Some(first_content) = content.first() {
-                    sanitize_filename(&first_content.title)
-                } else {
-                    "content".to_string()
+                let title = content
+                    .first()
+                    .map(|first_content| sanitize_filename(&first_content.title))
+                    .unwrap_or_else(|| "content".to_string());
+                match session_id {
+                    Some(session_id) => format!("{}_{}", title, session_id),
+                    None => title,
                 }
             }
             NamingStrategy::Sequential => {
-                format!("export_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S"))
+                let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+                match session_id {
+                    Some(session_id) => format!("export_{}_{}", timestamp, session_id),
+                    None => format!("export_{}", timestamp),
+                }
             }
             NamingStrategy::Custom(pattern) => {
                 // Simple placeholder replacement
@@ -345,9 +715,15 @@ impl BatchExportManager {
                 result = result.replace("{job_id}", &job.job_id);
                 result = result.replace("{timestamp}", &chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string());
                 result = result.replace("{format}", &format!("{:?}", format).to_lowercase());
+                result = result.replace("{session_id}", session_id.unwrap_or("merged"));
                 if let Some(first_content) = content.first() {
                     result = result.replace("{title}", &sanitize_filename(&first_content.title));
                 }
+                if let Some(session_id) = session_id {
+                    if !pattern.contains("{session_id}") {
+                        result = format!("{}_{}", result, session_id);
+                    }
+                }
                 result
             }
         };
@@ -355,49 +731,180 @@ impl BatchExportManager {
         Ok(format!("{}.{}", base_name, extension))
     }
 
-    async fn create_manifest(
+    /// Writes `batch_export_manifest.json`, merging with any prior manifest at that path
+    /// (keyed by `job_id`) rather than overwriting it, so a resumed or incremental run keeps
+    /// the history of jobs that already succeeded in an earlier invocation. Each export entry
+    /// records a `file_checksum` so the manifest can be verified against the files on disk, and
+    /// its own `source_checksum` (of just that export's session group, not the whole job) that
+    /// incremental runs compare against to decide whether that specific export needs
+    /// re-rendering. `job.source_checksum` is kept too, as a whole-job summary.
+    pub(crate) async fn create_manifest(
         &self,
         job_results: &[JobResult],
         _options: &BatchExportOptions,
     ) -> Result<PathBuf> {
-        let manifest = serde_json::json!({
-            "batch_export_manifest": {
-                "created_at": chrono::Utc::now().to_rfc3339(),
-                "total_jobs": job_results.len(),
-                "successful_jobs": job_results.iter().filter(|r| r.success).count(),
-                "failed_jobs": job_results.iter().filter(|r| !r.success).count(),
-                "jobs": job_results.iter().map(|job| {
+        let manifest_path = PathBuf::from(MANIFEST_FILENAME);
+        let mut jobs_by_id = read_manifest_jobs();
+
+        for job in job_results {
+            let entry = serde_json::json!({
+                "job_id": job.job_id,
+                "success": job.success,
+                "files_created": job.files_created,
+                "total_size": job.total_size,
+                "source_checksum": job.source_checksum,
+                "exports": job.export_results.iter().map(|export| {
                     serde_json::json!({
-                        "job_id": job.job_id,
-                        "success": job.success,
-                        "files_created": job.files_created,
-                        "total_size": job.total_size,
-                        "exports": job.export_results.iter().map(|export| {
-                            serde_json::json!({
-                                "success": export.success,
-                                "output_path": export.output_path,
-                                "file_size": export.file_size,
-                                "error_message": export.error_message
-                            })
-                        }).collect::<Vec<_>>()
+                        "success": export.success,
+                        "output_path": export.output_path,
+                        "file_size": export.file_size,
+                        "error_message": export.error_message,
+                        "file_checksum": export.file_checksum,
+                        "skipped": export.skipped,
+                        "session_id": export.session_id,
+                        "source_checksum": export.source_checksum
                     })
                 }).collect::<Vec<_>>()
+            });
+            jobs_by_id.insert(job.job_id.clone(), entry);
+        }
+
+        let jobs: Vec<serde_json::Value> = jobs_by_id.into_values().collect();
+        let successful_jobs = jobs.iter().filter(|j| j["success"] == true).count();
+        let failed_jobs = jobs.len() - successful_jobs;
+
+        let manifest = serde_json::json!({
+            "batch_export_manifest": {
+                "updated_at": chrono::Utc::now().to_rfc3339(),
+                "total_jobs": jobs.len(),
+                "successful_jobs": successful_jobs,
+                "failed_jobs": failed_jobs,
+                "jobs": jobs
             }
         });
 
-        let manifest_path = PathBuf::from("batch_export_manifest.json");
         std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
-        
+
         Ok(manifest_path)
     }
 
-    fn send_progress(&self, progress: BatchProgress) {
+    pub(crate) fn send_progress(&self, progress: BatchProgress) {
         if let Some(sender) = &self.progress_sender {
             let _ = sender.send(progress);
         }
     }
 }
 
+/// Awaits a `RunningJob` known to have already finished (or be about to), turning a panicked or
+/// aborted task into a `failed_job_result` instead of propagating the `JoinError`. Shared by
+/// `poll_completed` and `cancel`, both of which need to report a finished job's real result.
+async fn await_finished_job(running_job: RunningJob) -> JobResult {
+    match running_job.handle.await {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => failed_job_result(running_job.job_id, e.to_string()),
+        Err(join_error) => failed_job_result(running_job.job_id, join_error.to_string()),
+    }
+}
+
+/// A `JobResult` for a job that didn't produce any exports, used by `poll_completed` and
+/// `cancel` to report a panicked, aborted, or cancelled `append_job` task.
+fn failed_job_result(job_id: String, error_message: String) -> JobResult {
+    JobResult {
+        job_id,
+        success: false,
+        export_results: vec![],
+        error_message: Some(error_message),
+        files_created: 0,
+        total_size: 0,
+        source_checksum: None,
+    }
+}
+
+/// SHA-256 of a job's concatenated source content, used to detect whether the material behind
+/// a `(job, format)` pair has changed since the last manifest.
+fn content_checksum(content: &[GeneratedContent]) -> String {
+    let mut hasher = Sha256::new();
+    for item in content {
+        hasher.update(item.content.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// SHA-256 of a file's bytes, used to verify an exported file against its manifest entry. Returns
+/// `None` if the file can't be read (e.g. it was deleted after export).
+fn file_checksum(path: &PathBuf) -> Option<String> {
+    let bytes = std::fs::read(path).ok()?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Some(format!("{:x}", hasher.finalize()))
+}
+
+/// Jobs recorded in the prior `batch_export_manifest.json`, keyed by `job_id`. Returns an empty
+/// map if no manifest exists yet or it can't be parsed.
+fn read_manifest_jobs() -> std::collections::BTreeMap<String, serde_json::Value> {
+    let manifest_path = PathBuf::from(MANIFEST_FILENAME);
+    let Ok(existing) = std::fs::read_to_string(&manifest_path) else {
+        return std::collections::BTreeMap::new();
+    };
+    let Ok(existing) = serde_json::from_str::<serde_json::Value>(&existing) else {
+        return std::collections::BTreeMap::new();
+    };
+    existing["batch_export_manifest"]["jobs"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|entry| entry["job_id"].as_str().map(|id| (id.to_string(), entry)))
+        .collect()
+}
+
+/// If the job entry has a prior export for `output_path` whose own `source_checksum` matches
+/// `group_checksum` (the checksum of just this export's session group, not the whole job) and
+/// whose recorded `file_checksum` still matches the file on disk, returns the `ExportResult` to
+/// reuse instead of re-rendering. Comparing per-export rather than against the job's overall
+/// checksum means editing one session in a fan-out job only invalidates that session's exports.
+fn reusable_export(
+    job_entry: &serde_json::Value,
+    output_path: &PathBuf,
+    group_checksum: &str,
+) -> Option<super::ExportResult> {
+    let output_path_str = output_path.to_string_lossy();
+    let prior_export = job_entry["exports"].as_array()?.iter().find(|export| {
+        export["output_path"].as_str() == Some(output_path_str.as_ref())
+    })?;
+
+    if prior_export["source_checksum"].as_str() != Some(group_checksum) {
+        return None;
+    }
+
+    let prior_checksum = prior_export["file_checksum"].as_str()?;
+    if file_checksum(output_path).as_deref() != Some(prior_checksum) {
+        return None;
+    }
+
+    Some(super::ExportResult {
+        success: true,
+        output_path: output_path.clone(),
+        file_size: std::fs::metadata(output_path).ok().map(|m| m.len()),
+        error_message: None,
+        file_checksum: Some(prior_checksum.to_string()),
+        skipped: true,
+        session_id: None,
+        source_checksum: Some(group_checksum.to_string()),
+    })
+}
+
+/// Whether an `export_content` failure is worth retrying. By the time an error reaches the
+/// per-format retry loop, empty-content and invalid-session-id cases have already short-circuited
+/// earlier in `execute_single_job`; the errors that can actually land here are an unsupported
+/// export format (a configuration problem that will fail identically on every attempt, so it
+/// short-circuits without spending the retry budget) or an I/O failure writing the output file
+/// (assumed transient, e.g. a momentary disk or permission hiccup).
+fn is_transient_export_error(error: &anyhow::Error) -> bool {
+    let message = error.to_string().to_lowercase();
+    !message.contains("unsupported export format")
+}
+
 // Utility function to sanitize filenames
 fn sanitize_filename(filename: &str) -> String {
     filename
@@ -447,4 +954,25 @@ mod tests {
         let sanitized = sanitize_filename(&content[0].title);
         assert_eq!(sanitized, "Test Content");
     }
+
+    #[test]
+    fn test_transient_error_classification() {
+        let unsupported = anyhow::anyhow!("Unsupported export format: {:?}", ExportFormat::Markdown);
+        assert!(!is_transient_export_error(&unsupported));
+
+        let io_failure = anyhow::anyhow!("Failed to write markdown file").context("disk full");
+        assert!(is_transient_export_error(&io_failure));
+    }
+
+    #[test]
+    fn test_content_checksum_changes_with_content() {
+        let content = vec![create_test_content()];
+        let checksum = content_checksum(&content);
+
+        let mut changed = content.clone();
+        changed[0].content.push_str(" edited");
+
+        assert_eq!(checksum, content_checksum(&content));
+        assert_ne!(checksum, content_checksum(&changed));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,165 @@
+use super::{
+    BatchExportJob, BatchExportManager, BatchExportOptions, BatchExportQueue, BatchExportResult,
+    CronExpr, JobResult, RetryPolicy, ScheduledExport, Scheduler,
+};
+use std::sync::Arc;
+use tauri::State;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Managed state for the recurring export scheduler, resumed from its schedule file at startup
+/// in `main.rs` so entries survive a restart. Wrapped in an `Arc` (rather than bare `Mutex`,
+/// like `ExportQueueState`) so `start_scheduler` can hold its own clone for as long as its
+/// background firing loop runs.
+pub type SchedulerState = Arc<Mutex<Scheduler>>;
+
+/// Managed state for the persistent batch export queue, resumed from its journal at startup
+/// in `main.rs` so a crashed run's unfinished jobs are picked up again.
+pub type ExportQueueState = Mutex<BatchExportQueue>;
+
+/// Add a job to the persistent export queue without running it yet. Jobs are drained
+/// highest-`priority` first the next time `run_batch_export_queue` is called.
+#[tauri::command]
+pub async fn enqueue_batch_export_job(
+    queue: State<'_, ExportQueueState>,
+    job: BatchExportJob,
+) -> Result<(), String> {
+    queue.lock().await.enqueue(job).map_err(|e| e.to_string())
+}
+
+/// Drain all pending jobs in the queue, highest priority first, persisting progress to the
+/// journal as it goes so the run can be resumed if the process is interrupted.
+#[tauri::command]
+pub async fn run_batch_export_queue(
+    queue: State<'_, ExportQueueState>,
+    options: Option<BatchExportOptions>,
+) -> Result<BatchExportResult, String> {
+    let options = options.unwrap_or_default();
+    queue
+        .lock()
+        .await
+        .run(&options)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Run `jobs` once and then keep re-exporting them whenever one of their source sessions
+/// changes, until `cancel_export_watch` is called with the returned task id. Runs in the
+/// background so the caller isn't blocked on a loop that runs forever.
+#[tauri::command]
+pub async fn start_export_watch(
+    batch_manager: State<'_, Arc<BatchExportManager>>,
+    jobs: Vec<BatchExportJob>,
+    options: Option<BatchExportOptions>,
+) -> Result<String, String> {
+    let options = options.unwrap_or_default();
+    Ok(batch_manager.start_watch(jobs, options))
+}
+
+/// Stop a running `start_export_watch` task. Returns `false` if `task_id` wasn't a running
+/// watch task.
+#[tauri::command]
+pub async fn cancel_export_watch(
+    batch_manager: State<'_, Arc<BatchExportManager>>,
+    task_id: String,
+) -> Result<bool, String> {
+    Ok(batch_manager.cancel_watch(&task_id))
+}
+
+/// Submit a job for export without waiting for it to finish, returning a handle id. Poll
+/// `poll_completed_export_jobs` to collect its result once it's done, so a GUI can submit a
+/// batch of jobs and keep rendering a live queue instead of blocking on each one.
+#[tauri::command]
+pub async fn append_export_job(
+    batch_manager: State<'_, Arc<BatchExportManager>>,
+    job: BatchExportJob,
+    retry_policy: Option<RetryPolicy>,
+) -> Result<String, String> {
+    let handle_id = batch_manager.append_job(job, retry_policy.unwrap_or_default());
+    Ok(handle_id.to_string())
+}
+
+/// Drain the results of jobs submitted via `append_export_job` that have finished or been
+/// cancelled since the last call, without blocking on jobs still in flight.
+#[tauri::command]
+pub async fn poll_completed_export_jobs(
+    batch_manager: State<'_, Arc<BatchExportManager>>,
+) -> Result<Vec<(String, JobResult)>, String> {
+    Ok(batch_manager
+        .poll_completed()
+        .await
+        .into_iter()
+        .map(|(id, result)| (id.to_string(), result))
+        .collect())
+}
+
+/// Abort a job submitted via `append_export_job`. A no-op if `handle_id` isn't a running job.
+#[tauri::command]
+pub async fn cancel_export_job(
+    batch_manager: State<'_, Arc<BatchExportManager>>,
+    handle_id: String,
+) -> Result<(), String> {
+    let handle_id = Uuid::parse_str(&handle_id).map_err(|e| e.to_string())?;
+    batch_manager.cancel(handle_id).await;
+    Ok(())
+}
+
+/// Add a recurring export on `cron_expr`'s cadence (standard 5-field cron: minute hour
+/// day-of-month month day-of-week), returning the generated schedule id.
+#[tauri::command]
+pub async fn add_scheduled_export(
+    scheduler: State<'_, SchedulerState>,
+    job: BatchExportJob,
+    cron_expr: String,
+) -> Result<String, String> {
+    let schedule = CronExpr::parse(&cron_expr).map_err(|e| e.to_string())?;
+    scheduler.lock().await.add(job, schedule).map_err(|e| e.to_string())
+}
+
+/// Remove a recurring export added via `add_scheduled_export`.
+#[tauri::command]
+pub async fn remove_scheduled_export(
+    scheduler: State<'_, SchedulerState>,
+    schedule_id: String,
+) -> Result<(), String> {
+    scheduler.lock().await.remove(&schedule_id).map_err(|e| e.to_string())
+}
+
+/// List every currently scheduled recurring export, so a GUI can show which one is due next
+/// (and, while `start_scheduler` is active, which is currently firing).
+#[tauri::command]
+pub async fn list_scheduled_exports(
+    scheduler: State<'_, SchedulerState>,
+) -> Result<Vec<ScheduledExport>, String> {
+    Ok(scheduler.lock().await.entries().to_vec())
+}
+
+/// Start firing scheduled exports in the background on their cron cadence. Holds the
+/// scheduler's lock only to read the next fire time and while a due entry fires, not across the
+/// wait in between, so `add_scheduled_export`/`remove_scheduled_export` keep working on the same
+/// schedule between fires (a call made during a fire waits for it to finish). Calling this more
+/// than once starts an additional, redundant firing loop; callers should only call it once per app run.
+#[tauri::command]
+pub async fn start_scheduler(
+    scheduler: State<'_, SchedulerState>,
+    options: Option<BatchExportOptions>,
+) -> Result<(), String> {
+    let options = options.unwrap_or_default();
+    let scheduler = Arc::clone(&scheduler);
+
+    tokio::spawn(async move {
+        loop {
+            let wait = scheduler.lock().await.time_until_next();
+            match wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => tokio::time::sleep(std::time::Duration::from_secs(60)).await,
+            }
+
+            if let Err(e) = scheduler.lock().await.fire_soonest_if_due(&options).await {
+                eprintln!("Scheduled export failed to fire: {}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
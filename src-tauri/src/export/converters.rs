@@ -79,6 +79,23 @@ pub struct ExportResult {
     pub output_path: PathBuf,
     pub file_size: Option<u64>,
     pub error_message: Option<String>,
+    /// SHA-256 of the exported file's bytes, filled in by `BatchExportManager` after a
+    /// successful (or reused) export so the manifest can verify files on disk.
+    #[serde(default)]
+    pub file_checksum: Option<String>,
+    /// Set by `BatchExportManager` when an incremental run reused a prior export instead of
+    /// re-rendering it.
+    #[serde(default)]
+    pub skipped: bool,
+    /// The session this export covers, set when `BatchExportJob::merge_sessions` is `false` and
+    /// `BatchExportManager` fans a job out into one export set per session. `None` for merged jobs.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// SHA-256 of this export's own source content (its session group, not the whole job) used
+    /// by an incremental run to decide whether to re-render just this export, independent of
+    /// whether other sessions in the same fan-out job changed.
+    #[serde(default)]
+    pub source_checksum: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +109,9 @@ pub struct BatchExportJob {
     pub template_name: Option<String>,
     pub include_metadata: bool,
     pub branding_options: Option<BrandingOptions>,
+    /// Higher values are drained first by `BatchExportQueue`; ties keep insertion order.
+    #[serde(default)]
+    pub priority: u8,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -115,6 +135,13 @@ pub struct BatchExportOptions {
     pub continue_on_error: bool,
     pub create_manifest: bool,
     pub compress_output: bool,
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// When set, `execute_single_job` skips re-rendering a `(job, format)` pair whose source
+    /// content and output file are both unchanged since the last manifest, reusing the prior
+    /// `ExportResult` instead.
+    #[serde(default)]
+    pub incremental: bool,
 }
 
 impl Default for BatchExportOptions {
@@ -125,10 +152,41 @@ impl Default for BatchExportOptions {
             continue_on_error: true,
             create_manifest: true,
             compress_output: false,
+            retry_policy: RetryPolicy::default(),
+            incremental: false,
         }
     }
 }
 
+/// Controls how `execute_single_job` retries a transient `export_manager.export_content`
+/// failure (e.g. an I/O hiccup) before giving up on that `(job, format)` pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: std::time::Duration,
+    pub max_delay: std::time::Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay to wait before the given (0-indexed) retry attempt, capped at `max_delay`.
+    pub fn delay_for_attempt(&self, attempt: u32) -> std::time::Duration {
+        let scaled = self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        std::time::Duration::from_secs_f64(scaled).min(self.max_delay)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchExportResult {
     pub total_jobs: usize,
@@ -149,6 +207,11 @@ pub struct JobResult {
     pub error_message: Option<String>,
     pub files_created: usize,
     pub total_size: u64,
+    /// SHA-256 of the job's concatenated source `GeneratedContent` (all of its sessions
+    /// together), the same for every format in `export_results`. Recorded in the manifest as a
+    /// whole-job summary; incremental runs compare each export's own `source_checksum` instead.
+    #[serde(default)]
+    pub source_checksum: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,10 +8,13 @@ pub mod word;
 pub mod quarto;
 pub mod manager;
 pub mod batch;
+pub mod queue;
+pub mod schedule;
+pub mod commands;
 
 pub use converters::{
     ExportFormat, ExportOptions, ExportResult, FormatConverter, BrandingOptions, BrandColors, BrandFonts,
-    BatchExportJob, BatchExportOptions, BatchExportResult, JobResult, BatchProgress, NamingStrategy
+    BatchExportJob, BatchExportOptions, BatchExportResult, JobResult, BatchProgress, NamingStrategy, RetryPolicy
 };
 pub use markdown::MarkdownConverter;
 pub use html::HtmlConverter;
@@ -21,4 +24,6 @@ pub use word::WordConverter;
 #[cfg(feature = "quarto-integration")]
 pub use quarto::QuartoConverter;
 pub use manager::ExportManager;
-pub use batch::BatchExportManager;
\ No newline at end of file
+pub use batch::BatchExportManager;
+pub use queue::{BatchExportQueue, JobState, QueuedJob};
+pub use schedule::{CronExpr, ScheduledExport, Scheduler};
\ No newline at end of file
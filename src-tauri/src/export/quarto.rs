@@ -488,6 +488,10 @@ impl QuartoConverter {
                 output_path: qmd_path.clone(),
                 file_size: None,
                 error_message: Some(format!("Quarto render failed: {}", error_msg)),
+                file_checksum: None,
+                skipped: false,
+                session_id: None,
+                source_checksum: None,
             });
         }
 
@@ -512,6 +516,10 @@ impl QuartoConverter {
             output_path,
             file_size,
             error_message: None,
+            file_checksum: None,
+            skipped: false,
+            session_id: None,
+            source_checksum: None,
         })
     }
 
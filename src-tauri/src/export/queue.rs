@@ -0,0 +1,242 @@
+use super::{BatchExportJob, BatchExportManager, BatchExportOptions, BatchExportResult, JobResult};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Where a queued job currently stands. Persisted to the journal before and after it runs
+/// so a crashed process can tell which jobs still need doing on restart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobState {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedJob {
+    pub job: BatchExportJob,
+    pub state: JobState,
+    pub result: Option<JobResult>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Journal {
+    jobs: Vec<QueuedJob>,
+}
+
+/// A persistent, resumable queue of batch export jobs. Each job's state is written to a
+/// JSON journal before and after it runs, so a process that dies mid-run can pick up where
+/// it left off via `resume_from_journal` instead of re-exporting everything from scratch.
+/// Jobs are drained highest-`priority` first; ties keep their queued order.
+pub struct BatchExportQueue {
+    batch_manager: BatchExportManager,
+    journal_path: PathBuf,
+    jobs: Vec<QueuedJob>,
+}
+
+impl BatchExportQueue {
+    pub fn new(batch_manager: BatchExportManager, journal_path: impl Into<PathBuf>) -> Self {
+        Self {
+            batch_manager,
+            journal_path: journal_path.into(),
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Rebuild a queue from a prior run's journal. Jobs already marked `Succeeded` are kept
+    /// (so the journal and the eventual `BatchExportResult` still account for them) but are not
+    /// re-enqueued; a `Running` job from a crashed run is treated as `Pending` so `run` retries
+    /// it. When `retry_failed` is `true` (the usual case — a resumed run should re-enqueue every
+    /// job not yet marked `Succeeded`), a `Failed` job is treated as `Pending` too, so a job that
+    /// failed on a transient error in a prior run gets another chance the next time `run` is
+    /// called; pass `false` to leave `Failed` jobs alone and only pick back up where the process
+    /// was interrupted mid-job.
+    pub fn resume_from_journal(
+        batch_manager: BatchExportManager,
+        journal_path: impl Into<PathBuf>,
+        retry_failed: bool,
+    ) -> Result<Self> {
+        let journal_path = journal_path.into();
+        let journal = Self::read_journal(&journal_path)?;
+
+        let jobs = journal
+            .jobs
+            .into_iter()
+            .map(|mut queued| {
+                queued.state = resumed_state(queued.state, retry_failed);
+                queued
+            })
+            .collect();
+
+        let mut queue = Self {
+            batch_manager,
+            journal_path,
+            jobs,
+        };
+        queue.persist()?;
+        Ok(queue)
+    }
+
+    pub fn enqueue(&mut self, job: BatchExportJob) -> Result<()> {
+        self.jobs.push(QueuedJob {
+            job,
+            state: JobState::Pending,
+            result: None,
+        });
+        self.persist()
+    }
+
+    /// Drain all pending jobs, highest priority first, persisting state transitions to the
+    /// journal as each job starts and completes, then merging all results into the manifest
+    /// once every pending job has run.
+    pub async fn run(&mut self, options: &BatchExportOptions) -> Result<BatchExportResult> {
+        let start_time = std::time::Instant::now();
+
+        let mut pending_indices: Vec<usize> = self
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, queued)| queued.state == JobState::Pending)
+            .map(|(index, _)| index)
+            .collect();
+        pending_indices.sort_by_key(|&index| std::cmp::Reverse(self.jobs[index].job.priority));
+
+        for index in pending_indices {
+            self.jobs[index].state = JobState::Running;
+            self.persist()?;
+
+            let job = self.jobs[index].job.clone();
+            let result = self
+                .batch_manager
+                .execute_single_job(job, &options.retry_policy, options.incremental)
+                .await;
+
+            let queued = &mut self.jobs[index];
+            match result {
+                Ok(job_result) => {
+                    queued.state = if job_result.success {
+                        JobState::Succeeded
+                    } else {
+                        JobState::Failed
+                    };
+                    queued.result = Some(job_result);
+                }
+                Err(e) => {
+                    queued.state = JobState::Failed;
+                    queued.result = Some(JobResult {
+                        job_id: queued.job.job_id.clone(),
+                        success: false,
+                        export_results: vec![],
+                        error_message: Some(e.to_string()),
+                        files_created: 0,
+                        total_size: 0,
+                        source_checksum: None,
+                    });
+
+                    if !options.continue_on_error {
+                        self.persist()?;
+                        return Err(e);
+                    }
+                }
+            }
+
+            self.persist()?;
+        }
+
+        let job_results: Vec<JobResult> = self
+            .jobs
+            .iter()
+            .filter_map(|queued| queued.result.clone())
+            .collect();
+
+        let total_jobs = self.jobs.len();
+        let successful_jobs = job_results.iter().filter(|r| r.success).count();
+        let failed_jobs = job_results.iter().filter(|r| !r.success).count();
+        let total_files_created = job_results.iter().map(|r| r.files_created).sum();
+        let total_size = job_results.iter().map(|r| r.total_size).sum();
+
+        let manifest_path = if options.create_manifest {
+            Some(self.batch_manager.create_manifest(&job_results, options).await?)
+        } else {
+            None
+        };
+
+        Ok(BatchExportResult {
+            total_jobs,
+            successful_jobs,
+            failed_jobs,
+            job_results,
+            total_files_created,
+            total_size,
+            elapsed_time: start_time.elapsed(),
+            manifest_path,
+        })
+    }
+
+    fn persist(&self) -> Result<()> {
+        if let Some(parent) = self.journal_path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)
+                    .context("Failed to create batch export journal directory")?;
+            }
+        }
+
+        let journal = Journal {
+            jobs: self.jobs.clone(),
+        };
+        std::fs::write(&self.journal_path, serde_json::to_string_pretty(&journal)?)
+            .context("Failed to write batch export journal")?;
+
+        Ok(())
+    }
+
+    fn read_journal(journal_path: &Path) -> Result<Journal> {
+        if !journal_path.exists() {
+            return Ok(Journal::default());
+        }
+
+        let content = std::fs::read_to_string(journal_path)
+            .context("Failed to read batch export journal")?;
+        serde_json::from_str(&content).context("Failed to parse batch export journal")
+    }
+}
+
+/// The state a queued job should resume in: a `Running` job from a crashed run always goes back
+/// to `Pending` so it gets picked up again, and a `Failed` job does too when `retry_failed` is
+/// set (the usual case, so a job that failed on a transient error gets another chance). Every
+/// other state (`Pending`, `Succeeded`, or `Failed` with `retry_failed` unset) is left as-is.
+fn resumed_state(state: JobState, retry_failed: bool) -> JobState {
+    match state {
+        JobState::Running => JobState::Pending,
+        JobState::Failed if retry_failed => JobState::Pending,
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_retries_failed_jobs_by_default() {
+        assert_eq!(resumed_state(JobState::Failed, true), JobState::Pending);
+    }
+
+    #[test]
+    fn test_resume_can_leave_failed_jobs_alone() {
+        assert_eq!(resumed_state(JobState::Failed, false), JobState::Failed);
+    }
+
+    #[test]
+    fn test_resume_always_requeues_running_jobs() {
+        assert_eq!(resumed_state(JobState::Running, false), JobState::Pending);
+        assert_eq!(resumed_state(JobState::Running, true), JobState::Pending);
+    }
+
+    #[test]
+    fn test_resume_leaves_pending_and_succeeded_jobs_as_is() {
+        assert_eq!(resumed_state(JobState::Pending, true), JobState::Pending);
+        assert_eq!(resumed_state(JobState::Succeeded, true), JobState::Succeeded);
+    }
+}
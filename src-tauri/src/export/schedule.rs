@@ -0,0 +1,362 @@
+use super::{BatchExportJob, BatchExportManager, BatchExportOptions, BatchProgress};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// One field of a 5-field cron expression, either `*` or an explicit set of allowed values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum CronField {
+    Any,
+    Values(Vec<u32>),
+}
+
+impl CronField {
+    fn matches(&self, value: u32) -> bool {
+        match self {
+            CronField::Any => true,
+            CronField::Values(values) => values.contains(&value),
+        }
+    }
+
+    fn parse(field: &str, min: u32, max: u32) -> Result<Self> {
+        if field == "*" {
+            return Ok(CronField::Any);
+        }
+
+        if let Some(step_str) = field.strip_prefix("*/") {
+            let step: u32 = step_str
+                .parse()
+                .context(format!("Invalid cron step: {}", field))?;
+            if step == 0 {
+                anyhow::bail!("Cron step must be greater than zero: {}", field);
+            }
+            return Ok(CronField::Values((min..=max).step_by(step as usize).collect()));
+        }
+
+        let mut values = Vec::new();
+        for part in field.split(',') {
+            let value: u32 = part
+                .trim()
+                .parse()
+                .context(format!("Invalid cron field value: {}", part))?;
+            if value < min || value > max {
+                anyhow::bail!("Cron field value {} out of range {}-{}", value, min, max);
+            }
+            values.push(value);
+        }
+        Ok(CronField::Values(values))
+    }
+}
+
+/// A standard 5-field cron expression (`minute hour day-of-month month day-of-week`), e.g.
+/// `0 2 * * *` for a nightly 2am run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CronExpr {
+    minute: CronField,
+    hour: CronField,
+    day_of_month: CronField,
+    month: CronField,
+    day_of_week: CronField,
+    raw: String,
+}
+
+impl CronExpr {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            anyhow::bail!(
+                "Cron expression must have 5 fields (minute hour day-of-month month day-of-week), got {}: {}",
+                fields.len(),
+                expr
+            );
+        }
+
+        Ok(Self {
+            minute: CronField::parse(fields[0], 0, 59)?,
+            hour: CronField::parse(fields[1], 0, 23)?,
+            day_of_month: CronField::parse(fields[2], 1, 31)?,
+            month: CronField::parse(fields[3], 1, 12)?,
+            day_of_week: CronField::parse(fields[4], 0, 6)?,
+            raw: expr.to_string(),
+        })
+    }
+
+    fn matches(&self, at: &DateTime<Utc>) -> bool {
+        self.minute.matches(at.minute())
+            && self.hour.matches(at.hour())
+            && self.day_of_month.matches(at.day())
+            && self.month.matches(at.month())
+            && self.day_of_week.matches(at.weekday().num_days_from_sunday())
+    }
+
+    /// The next minute-aligned time strictly after `from` that satisfies this expression.
+    /// Searches at most four years ahead, since a day-of-month/month combination that never
+    /// occurs (e.g. `0 0 30 2 *`, Feb 30th) would otherwise search forever.
+    pub fn next_after(&self, from: DateTime<Utc>) -> Result<DateTime<Utc>> {
+        let mut candidate = (from + ChronoDuration::minutes(1))
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))
+            .context("Failed to align candidate time to the start of a minute")?;
+        let limit = from + ChronoDuration::days(4 * 365);
+
+        while candidate <= limit {
+            if self.matches(&candidate) {
+                return Ok(candidate);
+            }
+            candidate = candidate + ChronoDuration::minutes(1);
+        }
+
+        anyhow::bail!(
+            "No matching run time found for cron expression `{}` within 4 years",
+            self.raw
+        )
+    }
+}
+
+/// A batch export that fires automatically on `schedule`'s cadence (e.g. nightly regeneration
+/// of course packets), tracked by `Scheduler`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledExport {
+    pub schedule_id: String,
+    pub job: BatchExportJob,
+    pub schedule: CronExpr,
+    pub next_run: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ScheduleFile {
+    entries: Vec<ScheduledExport>,
+}
+
+/// Runs a list of `ScheduledExport` entries on their cron cadence: sleeps until the soonest
+/// `next_run`, fires that entry through `BatchExportManager::execute_batch_export`, recomputes
+/// its `next_run`, and repeats. The schedule is persisted to `schedule_path` after every change
+/// so it survives restarts; on load, any entry whose `next_run` has already passed is rolled
+/// forward to its next future occurrence rather than firing immediately, unless `catch_up` was
+/// requested.
+pub struct Scheduler {
+    batch_manager: BatchExportManager,
+    schedule_path: PathBuf,
+    entries: Vec<ScheduledExport>,
+}
+
+impl Scheduler {
+    pub fn new(batch_manager: BatchExportManager, schedule_path: impl Into<PathBuf>) -> Self {
+        Self {
+            batch_manager,
+            schedule_path: schedule_path.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Rebuilds a scheduler from a prior run's persisted schedule. Unless `catch_up` is set, any
+    /// entry whose `next_run` is already in the past is advanced to its next future occurrence,
+    /// so its missed fire times are skipped. With `catch_up` set, such an entry fires once when
+    /// next polled and its `next_run` is then recomputed from the current time, so a process
+    /// that was down over several fire times catches up once, not repeatedly.
+    pub fn resume_from_file(
+        batch_manager: BatchExportManager,
+        schedule_path: impl Into<PathBuf>,
+        catch_up: bool,
+    ) -> Result<Self> {
+        let schedule_path = schedule_path.into();
+        let mut entries = Self::read_file(&schedule_path)?.entries;
+
+        if !catch_up {
+            let now = Utc::now();
+            for entry in &mut entries {
+                if entry.next_run <= now {
+                    entry.next_run = entry.schedule.next_after(now)?;
+                }
+            }
+        }
+
+        let scheduler = Self {
+            batch_manager,
+            schedule_path,
+            entries,
+        };
+        scheduler.persist()?;
+        Ok(scheduler)
+    }
+
+    /// Adds a recurring export, computing its first `next_run` from `schedule` relative to now,
+    /// and returns the generated schedule id.
+    pub fn add(&mut self, job: BatchExportJob, schedule: CronExpr) -> Result<String> {
+        let schedule_id = Uuid::new_v4().to_string();
+        let next_run = schedule.next_after(Utc::now())?;
+
+        self.entries.push(ScheduledExport {
+            schedule_id: schedule_id.clone(),
+            job,
+            schedule,
+            next_run,
+        });
+        self.persist()?;
+
+        Ok(schedule_id)
+    }
+
+    pub fn remove(&mut self, schedule_id: &str) -> Result<()> {
+        self.entries.retain(|entry| entry.schedule_id != schedule_id);
+        self.persist()
+    }
+
+    pub fn entries(&self) -> &[ScheduledExport] {
+        &self.entries
+    }
+
+    /// Sleeps until the soonest entry's `next_run`, fires it, and repeats forever. A fired
+    /// entry that fails to export is logged but does not stop the scheduler; its `next_run` is
+    /// recomputed regardless so future fire times aren't skipped.
+    ///
+    /// Holds `&mut self` for as long as the scheduler runs, so it's only suitable for a caller
+    /// that owns its own `Scheduler` outright. A caller sharing a `Scheduler` behind a lock
+    /// (e.g. so a GUI can add/remove entries while it runs) should instead poll
+    /// `time_until_next`/`fire_soonest_if_due` from a loop that re-acquires the lock each pass.
+    pub async fn run_forever(&mut self, options: &BatchExportOptions) -> Result<()> {
+        loop {
+            match self.time_until_next() {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    continue;
+                }
+            }
+
+            self.fire_soonest_if_due(options).await?;
+        }
+    }
+
+    /// How long until the soonest entry is due, or `None` if there are no entries at all. A
+    /// caller polling this in a loop (rather than holding `&mut self` across the wait, as
+    /// `run_forever` does) can interleave other mutations to the scheduler between calls.
+    pub fn time_until_next(&self) -> Option<Duration> {
+        let index = self.soonest_index()?;
+        Some(
+            (self.entries[index].next_run - Utc::now())
+                .to_std()
+                .unwrap_or(Duration::ZERO),
+        )
+    }
+
+    /// Fires the soonest entry if its `next_run` has arrived, recomputing its `next_run`
+    /// regardless of success. Returns whether an entry fired; `false` if there are no entries or
+    /// the soonest one isn't due yet.
+    pub async fn fire_soonest_if_due(&mut self, options: &BatchExportOptions) -> Result<bool> {
+        let Some(index) = self.soonest_index() else {
+            return Ok(false);
+        };
+        if self.entries[index].next_run > Utc::now() {
+            return Ok(false);
+        }
+
+        self.fire(index, options).await?;
+        Ok(true)
+    }
+
+    fn soonest_index(&self) -> Option<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, entry)| entry.next_run)
+            .map(|(index, _)| index)
+    }
+
+    async fn fire(&mut self, index: usize, options: &BatchExportOptions) -> Result<()> {
+        let schedule_id = self.entries[index].schedule_id.clone();
+        let job = self.entries[index].job.clone();
+
+        self.batch_manager.send_progress(BatchProgress {
+            total_jobs: 1,
+            completed_jobs: 0,
+            current_job_id: Some(job.job_id.clone()),
+            current_operation: format!(
+                "Firing scheduled export {} (job {})",
+                schedule_id, job.job_id
+            ),
+            progress_percent: 0.0,
+            estimated_completion: None,
+            errors_encountered: 0,
+        });
+
+        if let Err(e) = self
+            .batch_manager
+            .execute_batch_export(vec![job], options.clone())
+            .await
+        {
+            eprintln!("Scheduled export {} failed: {}", schedule_id, e);
+        }
+
+        self.entries[index].next_run = self.entries[index].schedule.next_after(Utc::now())?;
+        self.persist()
+    }
+
+    fn read_file(path: &Path) -> Result<ScheduleFile> {
+        if !path.exists() {
+            return Ok(ScheduleFile::default());
+        }
+
+        let content = std::fs::read_to_string(path).context("Failed to read schedule file")?;
+        serde_json::from_str(&content).context("Failed to parse schedule file")
+    }
+
+    fn persist(&self) -> Result<()> {
+        if let Some(parent) = self.schedule_path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent).context("Failed to create schedule directory")?;
+            }
+        }
+
+        let file = ScheduleFile {
+            entries: self.entries.clone(),
+        };
+        std::fs::write(&self.schedule_path, serde_json::to_string_pretty(&file)?)
+            .context("Failed to write schedule file")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_wrong_field_count() {
+        assert!(CronExpr::parse("0 2 * *").is_err());
+    }
+
+    #[test]
+    fn test_nightly_schedule_fires_at_2am() {
+        let schedule = CronExpr::parse("0 2 * * *").unwrap();
+        let from = Utc::now()
+            .with_hour(10)
+            .unwrap()
+            .with_minute(0)
+            .unwrap()
+            .with_second(0)
+            .unwrap();
+
+        let next = schedule.next_after(from).unwrap();
+        assert_eq!(next.hour(), 2);
+        assert_eq!(next.minute(), 0);
+        assert!(next > from);
+    }
+
+    #[test]
+    fn test_step_field_matches_every_n_minutes() {
+        let schedule = CronExpr::parse("*/15 * * * *").unwrap();
+        let from = Utc::now()
+            .with_minute(1)
+            .unwrap()
+            .with_second(0)
+            .unwrap();
+
+        let next = schedule.next_after(from).unwrap();
+        assert_eq!(next.minute() % 15, 0);
+    }
+}
@@ -883,6 +883,10 @@ impl FormatConverter for WordConverter {
             output_path: options.output_path.clone(),
             file_size: Some(file_size),
             error_message: None,
+            file_checksum: None,
+            skipped: false,
+            session_id: None,
+            source_checksum: None,
         })
     }
 }
@@ -58,7 +58,9 @@ fn main() {
             session::commands::get_session_statistics,
             session::commands::duplicate_session,
             session::commands::get_session_content,
-            
+            session::commands::repair_sessions,
+            session::commands::cancel_session_repair,
+
             // File management operations
             file_manager::commands::save_session_to_file,
             file_manager::commands::export_session_content,
@@ -83,7 +85,10 @@ fn main() {
             backup::commands::cleanup_old_backups,
             backup::commands::verify_backup_integrity,
             backup::commands::get_session_backups,
-            
+            backup::commands::export_session_archive,
+            backup::commands::import_session_archive,
+            backup::commands::cancel_archive_task,
+
             // Content generation
             commands::generate_content,
             
@@ -127,6 +132,25 @@ fn main() {
             // Batch Export
             commands::batch_export_content,
             commands::create_batch_export_job,
+
+            // Batch Export Queue
+            export::commands::enqueue_batch_export_job,
+            export::commands::run_batch_export_queue,
+
+            // Batch Export Watch Mode
+            export::commands::start_export_watch,
+            export::commands::cancel_export_watch,
+
+            // Non-blocking Batch Export Job Submission
+            export::commands::append_export_job,
+            export::commands::poll_completed_export_jobs,
+            export::commands::cancel_export_job,
+
+            // Recurring Scheduled Exports
+            export::commands::add_scheduled_export,
+            export::commands::remove_scheduled_export,
+            export::commands::list_scheduled_exports,
+            export::commands::start_scheduler,
             
             // API Key management
             commands::store_api_key,
@@ -321,6 +345,39 @@ fn main() {
                 None
             )));
             
+            // Initialize the persistent batch export queue, resuming any jobs left over from a
+            // prior run that was interrupted before it finished draining the journal.
+            let export_queue_session_manager = crate::session::SessionManager::new(Arc::clone(&shared_db));
+            let export_queue_batch_manager = crate::export::BatchExportManager::new(export_queue_session_manager);
+            let export_journal_path = app.path().app_data_dir()
+                .expect("Failed to get app data directory")
+                .join("batch_export_journal.json");
+            let export_queue = crate::export::BatchExportQueue::resume_from_journal(
+                export_queue_batch_manager,
+                export_journal_path,
+                true,
+            ).expect("Failed to resume batch export queue from journal");
+
+            // A single long-lived batch export manager backs append/poll/cancel-style job
+            // submission and watch-mode exports, both of which need their in-memory state
+            // (running jobs, watch tasks) to survive across separate command invocations.
+            let batch_export_manager_session = crate::session::SessionManager::new(Arc::clone(&shared_db));
+            let batch_export_manager = Arc::new(crate::export::BatchExportManager::new(batch_export_manager_session));
+
+            // Initialize the recurring export scheduler, resuming any schedule left over from a
+            // prior run and rolling forward any entry whose `next_run` already passed while the
+            // app was closed, rather than firing it immediately on startup.
+            let scheduler_session_manager = crate::session::SessionManager::new(Arc::clone(&shared_db));
+            let scheduler_batch_manager = crate::export::BatchExportManager::new(scheduler_session_manager);
+            let schedule_path = app.path().app_data_dir()
+                .expect("Failed to get app data directory")
+                .join("export_schedule.json");
+            let scheduler = crate::export::Scheduler::resume_from_file(
+                scheduler_batch_manager,
+                schedule_path,
+                false,
+            ).expect("Failed to resume export scheduler from schedule file");
+
             app.manage(app_state);
             app.manage(session_service);
             app.manage(file_service_arc);
@@ -329,6 +386,9 @@ fn main() {
             app.manage(git_service);
             app.manage(data_export_service);
             app.manage(maintenance_service);
+            app.manage(Mutex::new(export_queue));
+            app.manage(batch_export_manager);
+            app.manage(Arc::new(Mutex::new(scheduler)));
             
             #[cfg(debug_assertions)] // only include this code on debug builds
             {
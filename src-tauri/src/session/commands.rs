@@ -2,6 +2,8 @@ use super::storage::{SessionManager, Session, SessionConfig};
 use crate::content::{ContentRequest, GeneratedContent};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::sync::Mutex;
 use tauri::State;
 use uuid::Uuid;
@@ -10,6 +12,7 @@ use chrono::{DateTime, Utc};
 /// Global session service state
 pub struct SessionService {
     manager: Mutex<SessionManager>,
+    repair_cancel: Arc<AtomicBool>,
 }
 
 impl SessionService {
@@ -17,6 +20,7 @@ impl SessionService {
         let manager = SessionManager::new(shared_db);
         Self {
             manager: Mutex::new(manager),
+            repair_cancel: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -441,8 +445,177 @@ pub async fn get_session_content(
     Ok(content)
 }
 
+/// A single inconsistency found by `repair_sessions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRepairIssue {
+    pub id: String,
+    pub kind: SessionRepairIssueKind,
+    pub session_id: Option<Uuid>,
+    pub description: String,
+    pub affected_items: u32,
+    pub fixed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SessionRepairIssueKind {
+    OrphanedContent,
+    OrphanedCostRecord,
+    ContentCountMismatch,
+    TotalCostMismatch,
+}
+
+/// Progress update emitted while `repair_sessions` runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRepairProgress {
+    pub current_step: String,
+    pub progress_percentage: f32,
+    pub items_processed: usize,
+    pub total_items: usize,
+}
+
+/// Result of a `repair_sessions` pass
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRepairResult {
+    pub issues: Vec<SessionRepairIssue>,
+    pub cancelled: bool,
+}
+
+/// Scan for storage inconsistencies that partial writes (e.g. a failed `duplicate_session`
+/// or `add_content_to_session` call) can leave behind: `GeneratedContent`/cost rows whose
+/// parent session was deleted, and sessions whose reported content count or total cost
+/// drifts from what's actually stored. When `fix` is true, auto-fixable issues are repaired
+/// as they are found. Complements `get_session_statistics`, which trusts this data blindly.
+#[tauri::command]
+pub async fn repair_sessions(
+    fix: bool,
+    service: State<'_, SessionService>,
+    window: tauri::Window,
+) -> Result<SessionRepairResult, String> {
+    use tauri::Emitter;
+
+    service.repair_cancel.store(false, Ordering::SeqCst);
+    let cancel = Arc::clone(&service.repair_cancel);
+
+    let manager = service.manager.lock().await.clone();
+    let sessions = manager.list_sessions().await.map_err(|e| e.to_string())?;
+
+    let total_items = sessions.len() + 1; // +1 for the orphan scan
+    let mut items_processed = 0;
+    let mut issues = Vec::new();
+
+    let emit_progress = |current_step: String, items_processed: usize| {
+        let _ = window.emit(
+            "session-repair-progress",
+            &SessionRepairProgress {
+                current_step,
+                progress_percentage: (items_processed as f32 / total_items as f32) * 100.0,
+                items_processed,
+                total_items,
+            },
+        );
+    };
+
+    emit_progress("Scanning for orphaned content and cost records".to_string(), items_processed);
+    let (orphaned_content_ids, orphaned_cost_ids) = manager
+        .find_orphaned_content_and_cost_ids()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !orphaned_content_ids.is_empty() {
+        let fixed = fix && manager.delete_content_by_ids(&orphaned_content_ids).await.is_ok();
+        issues.push(SessionRepairIssue {
+            id: "orphaned_generated_content".to_string(),
+            kind: SessionRepairIssueKind::OrphanedContent,
+            session_id: None,
+            description: format!("{} generated content rows reference deleted sessions", orphaned_content_ids.len()),
+            affected_items: orphaned_content_ids.len() as u32,
+            fixed,
+        });
+    }
+
+    if !orphaned_cost_ids.is_empty() {
+        let fixed = fix && manager.delete_cost_records_by_ids(&orphaned_cost_ids).await.is_ok();
+        issues.push(SessionRepairIssue {
+            id: "orphaned_cost_records".to_string(),
+            kind: SessionRepairIssueKind::OrphanedCostRecord,
+            session_id: None,
+            description: format!("{} cost records reference deleted sessions", orphaned_cost_ids.len()),
+            affected_items: orphaned_cost_ids.len() as u32,
+            fixed,
+        });
+    }
+
+    items_processed += 1;
+
+    for session in &sessions {
+        if cancel.load(Ordering::SeqCst) {
+            return Ok(SessionRepairResult { issues, cancelled: true });
+        }
+
+        emit_progress(format!("Checking session {}", session.name), items_processed);
+
+        let stored_content = manager.get_session_content(session.id).await.map_err(|e| e.to_string())?;
+        let actual_count = manager.count_session_content(session.id).await.map_err(|e| e.to_string())?;
+        if stored_content.len() as u32 != actual_count {
+            // The gap is rows `get_session_content` silently dropped for failing to parse as
+            // JSON; deleting them (like orphan cleanup above) brings the two counts back in sync.
+            let malformed_ids = manager.find_malformed_content_ids(session.id).await.map_err(|e| e.to_string())?;
+            let fixed = fix && !malformed_ids.is_empty()
+                && manager.delete_content_by_ids(&malformed_ids).await.is_ok();
+            issues.push(SessionRepairIssue {
+                id: format!("content_count_mismatch_{}", session.id),
+                kind: SessionRepairIssueKind::ContentCountMismatch,
+                session_id: Some(session.id),
+                description: format!(
+                    "Session '{}' reports {} content items but {} are stored ({} unparseable)",
+                    session.name, stored_content.len(), actual_count, malformed_ids.len()
+                ),
+                affected_items: 1,
+                fixed,
+            });
+        }
+
+        let reported_total_cost = manager.get_session_cost(session.id).await.map_err(|e| e.to_string())?;
+        let cost_records = manager.get_session_cost_records(session.id).await.map_err(|e| e.to_string())?;
+        let summed_cost: f64 = cost_records.iter().filter_map(|r| r.cost_usd).sum();
+        // Floating-point addition isn't associative, so SQLite's `SUM(cost_usd)` (row order
+        // undefined) and this fold (over rows fetched `ORDER BY created_at`) can disagree by a
+        // tiny amount even when every record is accounted for; compare to the cent, not to
+        // `f64::EPSILON`, so that rounding noise isn't reported as real drift.
+        if (reported_total_cost - summed_cost).abs() > 0.005 {
+            issues.push(SessionRepairIssue {
+                id: format!("total_cost_mismatch_{}", session.id),
+                kind: SessionRepairIssueKind::TotalCostMismatch,
+                session_id: Some(session.id),
+                description: format!(
+                    "Session '{}' total cost ${:.4} drifts from the sum of its cost records (${:.4})",
+                    session.name, reported_total_cost, summed_cost
+                ),
+                affected_items: 1,
+                // Both values are independent aggregates over the same `llm_usage` rows, with no
+                // separate stored total to correct — a real mismatch here means one of the two
+                // queries is wrong, which repair can only report, not patch over.
+                fixed: false,
+            });
+        }
+
+        items_processed += 1;
+    }
+
+    emit_progress("Repair complete".to_string(), total_items);
+
+    Ok(SessionRepairResult { issues, cancelled: false })
+}
+
+/// Cancel a `repair_sessions` pass currently in progress
+#[tauri::command]
+pub async fn cancel_session_repair(service: State<'_, SessionService>) -> Result<(), String> {
+    service.repair_cancel.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
 /// Helper function to calculate session size in MB
-fn calculate_session_size(session: &Session) -> f64 {
+pub(crate) fn calculate_session_size(session: &Session) -> f64 {
     let session_json = serde_json::to_string(session).unwrap_or_default();
     let content_size: usize = session.generated_content.iter()
         .map(|c| c.content.len())
@@ -464,5 +637,7 @@ pub fn get_session_command_names() -> Vec<&'static str> {
         "get_session_statistics",
         "duplicate_session",
         "get_session_content",
+        "repair_sessions",
+        "cancel_session_repair",
     ]
 }
\ No newline at end of file